@@ -1,54 +1,192 @@
-#![allow(warnings)] 
+#![allow(warnings)]
 
 extern crate bit_vec;
 
 use std::iter::FromIterator;
+use std::ops::{Add, Sub, Mul};
 
 use bit_vec::BitVec;
 
-#[derive(PartialEq, Clone, Copy)]
-pub struct Point {
-    pub x: f64,
-    pub y: f64
+/// A 2D coordinate. Implement this for your own point type to run
+/// simplification directly over it, without converting into `Point2`.
+pub trait Point {
+    fn x(&self) -> f64;
+    fn y(&self) -> f64;
 }
 
-impl Point {
-    pub fn new(x: f64, y: f64) -> Self {
-        Point { x: x, y: y }
+/// Squared Euclidean distance between two points, derived from `Point`.
+pub trait SqDistance: Point {
+    fn sq_dist<Other: Point>(&self, other: &Other) -> f64 {
+        let dx = self.x() - other.x();
+        let dy = self.y() - other.y();
+        dx * dx + dy * dy
     }
+}
 
-    fn sq_seg_dist(&self, p1: &Point, p2: &Point) -> f64 {
-        let mut x = p1.x;
-        let mut y = p1.y;
-        let mut dx = p2.x - p1.x;
-        let mut dy = p2.y - p1.y;
+impl<T: Point> SqDistance for T {}
+
+/// Squared distance from a point to its projection onto the segment `p1p2`,
+/// derived from `Point`.
+pub trait SqSegDistance: Point {
+    fn sq_seg_dist<Other: Point>(&self, p1: &Other, p2: &Other) -> f64 {
+        let mut x = p1.x();
+        let mut y = p1.y();
+        let mut dx = p2.x() - p1.x();
+        let mut dy = p2.y() - p1.y();
 
         if dx != 0.0 || dy != 0.0 {
-            let t = ((self.x - p1.x) * dx + (self.y - p1.y) * dy) / (dx * dx + dy * dy);
+            let t = ((self.x() - p1.x()) * dx + (self.y() - p1.y()) * dy) / (dx * dx + dy * dy);
 
             if t > 1.0 {
-                x = p2.x;
-                y = p2.y;
+                x = p2.x();
+                y = p2.y();
             } else if t > 0.0 {
                 x += dx * t;
                 y += dy * t;
             }
         }
 
-        dx = self.x - x;
-        dy = self.y - y;
+        dx = self.x() - x;
+        dy = self.y() - y;
 
-        (dx * dx + dy * dy)
+        dx * dx + dy * dy
+    }
+}
+
+impl<T: Point> SqSegDistance for T {}
+
+/// The index-stack/BitVec walk shared by every Douglas-Peucker pass: given
+/// the number of points and a `sq_seg_dist(i, start_idx, end_idx)` callback,
+/// returns a mask of which indices survive. Callers differ only in how they
+/// measure the distance from a point to a segment (2D, 3D, ...).
+fn douglas_peucker_keep_mask<F: Fn(usize, usize, usize) -> f64>(
+    len: usize, sq_tolerance: f64, sq_seg_dist: F
+) -> BitVec {
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+    stack.push((0, len - 1));
+
+    let mut keep_elem_vec = BitVec::from_elem(len, true);
+
+    while !stack.is_empty() {
+        let (start_idx, end_idx) = stack.pop().unwrap();
+
+        let mut dmax: f64 = 0.0f64;
+        let mut max_idx: usize = start_idx;
+
+        for i in (start_idx + 1)..end_idx {
+            if keep_elem_vec.get(i) == Some(true) {
+                let seg_dist = sq_seg_dist(i, start_idx, end_idx);
+                if seg_dist > dmax {
+                    max_idx = i;
+                    dmax = seg_dist;
+                }
+            }
+        }
+
+        if dmax > sq_tolerance {
+            stack.push((start_idx, max_idx));
+            stack.push((max_idx, end_idx));
+        } else {
+            for i in (start_idx + 1)..end_idx {
+                keep_elem_vec.set(i, false);
+            }
+        }
+    }
+
+    keep_elem_vec
+}
+
+/// The concrete 2D point provided out of the box.
+#[derive(PartialEq, Clone, Copy)]
+pub struct Point2 {
+    pub x: f64,
+    pub y: f64
+}
+
+impl Point2 {
+    pub fn new(x: f64, y: f64) -> Self {
+        Point2 { x: x, y: y }
+    }
+
+    pub fn dot(&self, other: &Point2) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn cross(&self, other: &Point2) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn sq_norm(&self) -> f64 {
+        self.x * self.x + self.y * self.y
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.sq_norm().sqrt()
+    }
+
+    pub fn signum(&self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn abs(&self) -> Point2 {
+        Point2::new(self.x.abs(), self.y.abs())
+    }
+
+    pub fn rotate90(&self) -> Point2 {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// Applies the 2x2 matrix `[m0 m1; m2 m3]` to this point, then
+    /// translates the result by `translate`.
+    pub fn transform(&self, m: &[f64; 4], translate: Point2) -> Point2 {
+        Point2::new(
+            m[0] * self.x + m[1] * self.y + translate.x,
+            m[2] * self.x + m[3] * self.y + translate.y
+        )
+    }
+}
+
+impl Add for Point2 {
+    type Output = Point2;
+
+    fn add(self, other: Point2) -> Point2 {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Point2;
+
+    fn sub(self, other: Point2) -> Point2 {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<f64> for Point2 {
+    type Output = Point2;
+
+    fn mul(self, scalar: f64) -> Point2 {
+        Point2::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Point for Point2 {
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    fn y(&self) -> f64 {
+        self.y
     }
 }
 
 #[derive(PartialEq, Clone)]
-pub struct Polyline {
-    pub points: Vec<Point>
+pub struct Polyline<P: Point + Clone> {
+    pub points: Vec<P>
 }
 
-impl FromIterator<Point> for Polyline {
-    fn from_iter<I: IntoIterator<Item=Point>>(iterator: I) -> Self {
+impl<P: Point + Clone> FromIterator<P> for Polyline<P> {
+    fn from_iter<I: IntoIterator<Item=P>>(iterator: I) -> Self {
         let mut polyline = Polyline::new();
         for i in iterator {
             polyline.points.push(i);
@@ -57,12 +195,12 @@ impl FromIterator<Point> for Polyline {
     }
 }
 
-impl Polyline {
+impl<P: Point + Clone> Polyline<P> {
     pub fn new() -> Self {
         Polyline { points: Vec::new() }
     }
 
-	pub fn from_vec(vec: Vec<Point>) -> Self {
+	pub fn from_vec(vec: Vec<P>) -> Self {
         Polyline { points: vec }
     }
 
@@ -70,7 +208,7 @@ impl Polyline {
         self.points.len()
     }
 
-    fn add(&mut self, point: Point) {
+    fn add(&mut self, point: P) {
         self.points.push(point);
     }
 
@@ -78,8 +216,8 @@ impl Polyline {
         self.points.iter().take(self.points.len()-1)
             .zip(self.points.iter().skip(1))
             .filter(|&(pre, cur)| {
-                let dx = pre.x - cur.y;
-                let dy = pre.y - cur.y;
+                let dx = pre.x() - cur.x();
+                let dy = pre.y() - cur.y();
                 (dx * dx + dy * dy) > sq_tolerance
             })
             .map(|(pre, cur)| pre.clone())
@@ -87,40 +225,13 @@ impl Polyline {
     }
 
     fn simplify_douglas_peucker(&self, sq_tolerance: f64) -> Self {
-        let mut stack: Vec<(usize, usize)> = Vec::new();
-        stack.push((0, self.points.len() - 1));
-
-        let mut keep_elem_vec = BitVec::from_elem(self.points.len(), true);
-
-
-        while !stack.is_empty() {
-            let (start_idx, end_idx) = stack.pop().unwrap();
-
-            let mut dmax: f64 = 0.0f64;
-            let mut max_idx: usize = start_idx;
-
-            for i in (start_idx + 1)..end_idx {
-                if keep_elem_vec.get(i) == Some(true) {
-                    let seg_dist = self.points.get(i).unwrap()
-                        .sq_seg_dist(
-                            self.points.get(start_idx).unwrap(),
-                            self.points.get(end_idx).unwrap());
-                    if seg_dist > dmax {
-                        max_idx = i;
-                        dmax = seg_dist;
-                    }
-                }
+        let keep_elem_vec = douglas_peucker_keep_mask(
+            self.points.len(),
+            sq_tolerance,
+            |i, start_idx, end_idx| {
+                self.points[i].sq_seg_dist(&self.points[start_idx], &self.points[end_idx])
             }
-
-            if dmax > sq_tolerance {
-                stack.push((start_idx, max_idx));
-                stack.push((max_idx, end_idx));
-            } else {
-                for i in (start_idx + 1)..end_idx {
-                    keep_elem_vec.set(i, false);
-                }
-            }
-        }
+        );
 
         self.points.iter()
             .enumerate()
@@ -129,7 +240,7 @@ impl Polyline {
             .collect()
     }
 
-    pub fn simplify(&self, tolerance: f64, highest_quality: bool) -> Polyline {
+    pub fn simplify(&self, tolerance: f64, highest_quality: bool) -> Polyline<P> {
         if self.points.len() <= 2 {
             return self.clone();
         }
@@ -144,17 +255,15 @@ impl Polyline {
             self.simplify_radial_dist(sq_tolerance)
         }.simplify_douglas_peucker(sq_tolerance);
 
-        // TODO(talevy): port this simplification algorithm 
+        // TODO(talevy): port this simplification algorithm
         // out into its own method
-        let mut keep = Vec::with_capacity(self.points.len());
-        let mut it = self.points.iter();
+        let mut keep = Vec::with_capacity(poly.points.len());
+        let mut it = poly.points.iter();
         let mut q = it.next().unwrap();
         keep.push(q.clone());
 
         for p in it {
-            let dx = p.x - q.x;
-            let dy = p.y - q.y;
-            let d = (dx * dx + dy * dy);
+            let d = p.sq_dist(q);
 
             if d > 0.000009 {
                 keep.push(p.clone());
@@ -164,6 +273,204 @@ impl Polyline {
 
         Polyline::from_vec(keep)
     }
+
+    /// The convex hull of this polyline's points, in counter-clockwise
+    /// order, computed via Andrew's monotone chain.
+    pub fn convex_hull(&self) -> Polyline<P> {
+        if self.points.len() < 3 {
+            return self.clone();
+        }
+
+        let mut points = self.points.clone();
+        points.sort_by(|a, b| {
+            a.x().partial_cmp(&b.x()).unwrap()
+                .then(a.y().partial_cmp(&b.y()).unwrap())
+        });
+
+        fn cross<P: Point>(a: &P, b: &P, c: &P) -> f64 {
+            (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x())
+        }
+
+        let mut lower: Vec<P> = Vec::new();
+        for p in points.iter() {
+            while lower.len() >= 2 && cross(&lower[lower.len() - 2], &lower[lower.len() - 1], p) <= 0.0 {
+                lower.pop();
+            }
+            lower.push(p.clone());
+        }
+
+        let mut upper: Vec<P> = Vec::new();
+        for p in points.iter().rev() {
+            while upper.len() >= 2 && cross(&upper[upper.len() - 2], &upper[upper.len() - 1], p) <= 0.0 {
+                upper.pop();
+            }
+            upper.push(p.clone());
+        }
+
+        lower.pop();
+        upper.pop();
+        lower.extend(upper);
+
+        Polyline::from_vec(lower)
+    }
+}
+
+impl Polyline<Point2> {
+    /// Applies `Point2::transform` to every vertex.
+    pub fn transform(&self, m: &[f64; 4], translate: Point2) -> Polyline<Point2> {
+        self.points.iter()
+            .map(|p| p.transform(m, translate))
+            .collect()
+    }
+
+    /// The axis-aligned bounding box of this polyline's points, or `None`
+    /// if it has no points.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        let mut it = self.points.iter();
+        let first = match it.next() {
+            Some(p) => p,
+            None => return None
+        };
+
+        let mut min = *first;
+        let mut max = *first;
+
+        for p in it {
+            if p.x < min.x { min.x = p.x; }
+            if p.y < min.y { min.y = p.y; }
+            if p.x > max.x { max.x = p.x; }
+            if p.y > max.y { max.y = p.y; }
+        }
+
+        Some(Rect { min: min, max: max })
+    }
+}
+
+/// An axis-aligned bounding box.
+#[derive(PartialEq, Clone, Copy)]
+pub struct Rect {
+    pub min: Point2,
+    pub max: Point2
+}
+
+impl Rect {
+    pub fn contains(&self, p: &Point2) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+}
+
+impl Point2 {
+    pub fn clamp(&self, rect: &Rect) -> Point2 {
+        Point2::new(
+            self.x.max(rect.min.x).min(rect.max.x),
+            self.y.max(rect.min.y).min(rect.max.y)
+        )
+    }
+}
+
+/// A 3D point, used by `Polyline3` for linestrings with elevation.
+#[derive(PartialEq, Clone, Copy)]
+pub struct Point3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64
+}
+
+impl Point3 {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Point3 { x: x, y: y, z: z }
+    }
+
+    fn sq_seg_dist(&self, p1: &Point3, p2: &Point3) -> f64 {
+        let mut x = p1.x;
+        let mut y = p1.y;
+        let mut z = p1.z;
+        let mut dx = p2.x - p1.x;
+        let mut dy = p2.y - p1.y;
+        let mut dz = p2.z - p1.z;
+
+        if dx != 0.0 || dy != 0.0 || dz != 0.0 {
+            let t = ((self.x - p1.x) * dx + (self.y - p1.y) * dy + (self.z - p1.z) * dz)
+                / (dx * dx + dy * dy + dz * dz);
+
+            if t > 1.0 {
+                x = p2.x;
+                y = p2.y;
+                z = p2.z;
+            } else if t > 0.0 {
+                x += dx * t;
+                y += dy * t;
+                z += dz * t;
+            }
+        }
+
+        dx = self.x - x;
+        dy = self.y - y;
+        dz = self.z - z;
+
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+/// A 3D linestring, for GPS tracks with elevation or 3D CAD paths that the
+/// 2D-only `Polyline` would otherwise flatten.
+#[derive(PartialEq, Clone)]
+pub struct Polyline3 {
+    pub points: Vec<Point3>
+}
+
+impl FromIterator<Point3> for Polyline3 {
+    fn from_iter<I: IntoIterator<Item=Point3>>(iterator: I) -> Self {
+        let mut polyline = Polyline3::new();
+        for i in iterator {
+            polyline.points.push(i);
+        }
+        polyline
+    }
+}
+
+impl Polyline3 {
+    pub fn new() -> Self {
+        Polyline3 { points: Vec::new() }
+    }
+
+    pub fn from_vec(vec: Vec<Point3>) -> Self {
+        Polyline3 { points: vec }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    fn add(&mut self, point: Point3) {
+        self.points.push(point);
+    }
+
+    fn simplify_douglas_peucker(&self, sq_tolerance: f64) -> Self {
+        let keep_elem_vec = douglas_peucker_keep_mask(
+            self.points.len(),
+            sq_tolerance,
+            |i, start_idx, end_idx| {
+                self.points[i].sq_seg_dist(&self.points[start_idx], &self.points[end_idx])
+            }
+        );
+
+        self.points.iter()
+            .enumerate()
+            .filter(|&(i, p)| keep_elem_vec.get(i) == Some(true))
+            .map(|(i, p)| p.clone())
+            .collect()
+    }
+
+    pub fn simplify(&self, tolerance: f64) -> Polyline3 {
+        if self.points.len() <= 2 {
+            return self.clone();
+        }
+
+        let sq_tolerance = tolerance.powi(2);
+
+        self.simplify_douglas_peucker(sq_tolerance)
+    }
 }
 
 
@@ -172,13 +479,32 @@ mod tests {
     use super::*;
     use std::fmt;
 
-    impl fmt::Debug for Point {
+    impl fmt::Debug for Point2 {
         fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
             write!(fmt, "({},{})", self.x, self.y);
             Ok(())
         }
     }
-    impl fmt::Debug for Polyline {
+    impl<P: Point + Clone + fmt::Debug> fmt::Debug for Polyline<P> {
+        fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+            write!(fmt, "[");
+            for (i, p) in self.points.iter().enumerate() {
+                write!(fmt, "{:?}", p);
+                if i < self.points.len() - 1 {
+                    write!(fmt, ",");
+                }
+            }
+            write!(fmt, "]");
+            Ok(())
+        }
+    }
+    impl fmt::Debug for Point3 {
+        fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+            write!(fmt, "({},{},{})", self.x, self.y, self.z);
+            Ok(())
+        }
+    }
+    impl fmt::Debug for Polyline3 {
         fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
             write!(fmt, "[");
             for (i, p) in self.points.iter().enumerate() {
@@ -195,52 +521,156 @@ mod tests {
     #[test]
     fn does_nothing_with_two() {
         let mut line = Polyline::new();
-        line.add(Point::new(0.0, 0.0));
-        line.add(Point::new(1.0, 8.9));
+        line.add(Point2::new(0.0, 0.0));
+        line.add(Point2::new(1.0, 8.9));
         let new = line.simplify(5.0, true);
         assert_eq!("[(0,0),(1,8.9)]", format!("{:?}", new));
     }
 
+    #[test]
+    fn does_nothing_with_two_3d() {
+        let mut line = Polyline3::new();
+        line.add(Point3::new(0.0, 0.0, 0.0));
+        line.add(Point3::new(1.0, 8.9, 2.0));
+        let new = line.simplify(5.0);
+        assert_eq!("[(0,0,0),(1,8.9,2)]", format!("{:?}", new));
+    }
+
+    #[test]
+    fn convex_hull_of_square_with_interior_point() {
+        let line = Polyline::from_vec(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 2.0),
+            Point2::new(1.0, 1.0)
+        ]);
+        let hull = line.convex_hull();
+        assert_eq!("[(0,0),(2,0),(2,2),(0,2)]", format!("{:?}", hull));
+    }
+
+    #[test]
+    fn convex_hull_of_fewer_than_three_points_is_a_clone() {
+        let line = Polyline::from_vec(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0)
+        ]);
+        let hull = line.convex_hull();
+        assert_eq!(line, hull);
+    }
+
+    #[test]
+    fn convex_hull_of_collinear_points_is_the_two_extremes() {
+        let line = Polyline::from_vec(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(3.0, 3.0)
+        ]);
+        let hull = line.convex_hull();
+        assert_eq!("[(0,0),(3,3)]", format!("{:?}", hull));
+    }
+
+    #[test]
+    fn point_vector_ops() {
+        let a = Point2::new(1.0, 2.0);
+        let b = Point2::new(3.0, 4.0);
+
+        assert_eq!(11.0, a.dot(&b));
+        assert_eq!(-2.0, a.cross(&b));
+        assert_eq!(5.0, a.sq_norm());
+        assert_eq!(Point2::new(4.0, 6.0), a + b);
+        assert_eq!(Point2::new(-2.0, -2.0), a - b);
+        assert_eq!(Point2::new(2.0, 4.0), a * 2.0);
+        assert_eq!(Point2::new(-2.0, 1.0), a.rotate90());
+    }
+
+    #[test]
+    fn transforms_every_vertex() {
+        let line = Polyline::from_vec(vec![Point2::new(1.0, 0.0), Point2::new(0.0, 1.0)]);
+        // rotate 90 degrees counter-clockwise, then translate by (1, 1)
+        let rotated = line.transform(&[0.0, -1.0, 1.0, 0.0], Point2::new(1.0, 1.0));
+        assert_eq!("[(1,2),(0,1)]", format!("{:?}", rotated));
+    }
+
+    #[test]
+    fn bounding_box_and_containment() {
+        let line = Polyline::from_vec(vec![
+            Point2::new(1.0, 5.0),
+            Point2::new(-2.0, 1.0),
+            Point2::new(3.0, -4.0)
+        ]);
+        let rect = line.bounding_box().unwrap();
+
+        assert_eq!(Point2::new(-2.0, -4.0), rect.min);
+        assert_eq!(Point2::new(3.0, 5.0), rect.max);
+        assert!(rect.contains(&Point2::new(0.0, 0.0)));
+        assert!(!rect.contains(&Point2::new(10.0, 0.0)));
+    }
+
+    #[test]
+    fn bounding_box_of_empty_polyline() {
+        let line: Polyline<Point2> = Polyline::new();
+        assert!(line.bounding_box().is_none());
+    }
+
+    #[test]
+    fn clamp_pulls_point_into_rect() {
+        let rect = Rect { min: Point2::new(0.0, 0.0), max: Point2::new(10.0, 10.0) };
+        let clamped = Point2::new(-5.0, 20.0).clamp(&rect);
+        assert_eq!(Point2::new(0.0, 10.0), clamped);
+    }
+
+    #[test]
+    fn drops_collinear_point_3d() {
+        let line = Polyline3::from_vec(vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(2.0, 2.0, 2.0)
+        ]);
+        let new = line.simplify(0.1);
+        assert_eq!("[(0,0,0),(2,2,2)]", format!("{:?}", new));
+    }
+
     #[test]
     fn it_works() {
 		let original = Polyline::from_vec(vec![
-			Point {x:224.55,y:250.15},Point {x:226.91,y:244.19},Point {x:233.31,y:241.45},Point {x:234.98,y:236.06},
-			Point {x:244.21,y:232.76},Point {x:262.59,y:215.31},Point {x:267.76,y:213.81},Point {x:273.57,y:201.84},
-			Point {x:273.12,y:192.16},Point {x:277.62,y:189.03},Point {x:280.36,y:181.41},Point {x:286.51,y:177.74},
-			Point {x:292.41,y:159.37},Point {x:296.91,y:155.64},Point {x:314.95,y:151.37},Point {x:319.75,y:145.16},
-			Point {x:330.33,y:137.57},Point {x:341.48,y:139.96},Point {x:369.98,y:137.89},Point {x:387.39,y:142.51},
-			Point {x:391.28,y:139.39},Point {x:409.52,y:141.14},Point {x:414.82,y:139.75},Point {x:427.72,y:127.30},
-			Point {x:439.60,y:119.74},Point {x:474.93,y:107.87},Point {x:486.51,y:106.75},Point {x:489.20,y:109.45},
-			Point {x:493.79,y:108.63},Point {x:504.74,y:119.66},Point {x:512.96,y:122.35},Point {x:518.63,y:120.89},
-			Point {x:524.09,y:126.88},Point {x:529.57,y:127.86},Point {x:534.21,y:140.93},Point {x:539.27,y:147.24},
-			Point {x:567.69,y:148.91},Point {x:575.25,y:157.26},Point {x:580.62,y:158.15},Point {x:601.53,y:156.85},
-			Point {x:617.74,y:159.86},Point {x:622.00,y:167.04},Point {x:629.55,y:194.60},Point {x:638.90,y:195.61},
-			Point {x:641.26,y:200.81},Point {x:651.77,y:204.56},Point {x:671.55,y:222.55},Point {x:683.68,y:217.45},
-			Point {x:695.25,y:219.15},Point {x:700.64,y:217.98},Point {x:703.12,y:214.36},Point {x:712.26,y:215.87},
-			Point {x:721.49,y:212.81},Point {x:727.81,y:213.36},Point {x:729.98,y:208.73},Point {x:735.32,y:208.20},
-			Point {x:739.94,y:204.77},Point {x:769.98,y:208.42},Point {x:779.60,y:216.87},Point {x:784.20,y:218.16},
-			Point {x:800.24,y:214.62},Point {x:810.53,y:219.73},Point {x:817.19,y:226.82},Point {x:820.77,y:236.17},
-			Point {x:827.23,y:236.16},Point {x:829.89,y:239.89},Point {x:851.00,y:248.94},Point {x:859.88,y:255.49},
-			Point {x:865.21,y:268.53},Point {x:857.95,y:280.30},Point {x:865.48,y:291.45},Point {x:866.81,y:298.66},
-			Point {x:864.68,y:302.71},Point {x:867.79,y:306.17},Point {x:859.87,y:311.37},Point {x:860.08,y:314.35},
-			Point {x:858.29,y:314.94},Point {x:858.10,y:327.60},Point {x:854.54,y:335.40},Point {x:860.92,y:343.00},
-			Point {x:856.43,y:350.15},Point {x:851.42,y:352.96},Point {x:849.84,y:359.59},Point {x:854.56,y:365.53},
-			Point {x:849.74,y:370.38},Point {x:844.09,y:371.89},Point {x:844.75,y:380.44},Point {x:841.52,y:383.67},
-			Point {x:839.57,y:390.40},Point {x:845.59,y:399.05},Point {x:848.40,y:407.55},Point {x:843.71,y:411.30},
-			Point {x:844.09,y:419.88},Point {x:839.51,y:432.76},Point {x:841.33,y:441.04},Point {x:847.62,y:449.22},
-			Point {x:847.16,y:458.44},Point {x:851.38,y:462.79},Point {x:853.97,y:471.15},Point {x:866.36,y:480.77}
+			Point2 {x:224.55,y:250.15},Point2 {x:226.91,y:244.19},Point2 {x:233.31,y:241.45},Point2 {x:234.98,y:236.06},
+			Point2 {x:244.21,y:232.76},Point2 {x:262.59,y:215.31},Point2 {x:267.76,y:213.81},Point2 {x:273.57,y:201.84},
+			Point2 {x:273.12,y:192.16},Point2 {x:277.62,y:189.03},Point2 {x:280.36,y:181.41},Point2 {x:286.51,y:177.74},
+			Point2 {x:292.41,y:159.37},Point2 {x:296.91,y:155.64},Point2 {x:314.95,y:151.37},Point2 {x:319.75,y:145.16},
+			Point2 {x:330.33,y:137.57},Point2 {x:341.48,y:139.96},Point2 {x:369.98,y:137.89},Point2 {x:387.39,y:142.51},
+			Point2 {x:391.28,y:139.39},Point2 {x:409.52,y:141.14},Point2 {x:414.82,y:139.75},Point2 {x:427.72,y:127.30},
+			Point2 {x:439.60,y:119.74},Point2 {x:474.93,y:107.87},Point2 {x:486.51,y:106.75},Point2 {x:489.20,y:109.45},
+			Point2 {x:493.79,y:108.63},Point2 {x:504.74,y:119.66},Point2 {x:512.96,y:122.35},Point2 {x:518.63,y:120.89},
+			Point2 {x:524.09,y:126.88},Point2 {x:529.57,y:127.86},Point2 {x:534.21,y:140.93},Point2 {x:539.27,y:147.24},
+			Point2 {x:567.69,y:148.91},Point2 {x:575.25,y:157.26},Point2 {x:580.62,y:158.15},Point2 {x:601.53,y:156.85},
+			Point2 {x:617.74,y:159.86},Point2 {x:622.00,y:167.04},Point2 {x:629.55,y:194.60},Point2 {x:638.90,y:195.61},
+			Point2 {x:641.26,y:200.81},Point2 {x:651.77,y:204.56},Point2 {x:671.55,y:222.55},Point2 {x:683.68,y:217.45},
+			Point2 {x:695.25,y:219.15},Point2 {x:700.64,y:217.98},Point2 {x:703.12,y:214.36},Point2 {x:712.26,y:215.87},
+			Point2 {x:721.49,y:212.81},Point2 {x:727.81,y:213.36},Point2 {x:729.98,y:208.73},Point2 {x:735.32,y:208.20},
+			Point2 {x:739.94,y:204.77},Point2 {x:769.98,y:208.42},Point2 {x:779.60,y:216.87},Point2 {x:784.20,y:218.16},
+			Point2 {x:800.24,y:214.62},Point2 {x:810.53,y:219.73},Point2 {x:817.19,y:226.82},Point2 {x:820.77,y:236.17},
+			Point2 {x:827.23,y:236.16},Point2 {x:829.89,y:239.89},Point2 {x:851.00,y:248.94},Point2 {x:859.88,y:255.49},
+			Point2 {x:865.21,y:268.53},Point2 {x:857.95,y:280.30},Point2 {x:865.48,y:291.45},Point2 {x:866.81,y:298.66},
+			Point2 {x:864.68,y:302.71},Point2 {x:867.79,y:306.17},Point2 {x:859.87,y:311.37},Point2 {x:860.08,y:314.35},
+			Point2 {x:858.29,y:314.94},Point2 {x:858.10,y:327.60},Point2 {x:854.54,y:335.40},Point2 {x:860.92,y:343.00},
+			Point2 {x:856.43,y:350.15},Point2 {x:851.42,y:352.96},Point2 {x:849.84,y:359.59},Point2 {x:854.56,y:365.53},
+			Point2 {x:849.74,y:370.38},Point2 {x:844.09,y:371.89},Point2 {x:844.75,y:380.44},Point2 {x:841.52,y:383.67},
+			Point2 {x:839.57,y:390.40},Point2 {x:845.59,y:399.05},Point2 {x:848.40,y:407.55},Point2 {x:843.71,y:411.30},
+			Point2 {x:844.09,y:419.88},Point2 {x:839.51,y:432.76},Point2 {x:841.33,y:441.04},Point2 {x:847.62,y:449.22},
+			Point2 {x:847.16,y:458.44},Point2 {x:851.38,y:462.79},Point2 {x:853.97,y:471.15},Point2 {x:866.36,y:480.77}
 		]);
 
         let expected = Polyline::from_vec(vec![
-            Point {x:224.55,y:250.15},Point {x:267.76,y:213.81},Point {x:296.91,y:155.64},Point {x:330.33,y:137.57},
-            Point {x:409.52,y:141.14},Point {x:439.60,y:119.74},Point {x:486.51,y:106.75},Point {x:529.57,y:127.86},
-            Point {x:539.27,y:147.24},Point {x:617.74,y:159.86},Point {x:629.55,y:194.60},Point {x:671.55,y:222.55},
-            Point {x:727.81,y:213.36},Point {x:739.94,y:204.77},Point {x:769.98,y:208.42},Point {x:779.60,y:216.87},
-            Point {x:800.24,y:214.62},Point {x:820.77,y:236.17},Point {x:859.88,y:255.49},Point {x:865.21,y:268.53},
-            Point {x:857.95,y:280.30},Point {x:867.79,y:306.17},Point {x:859.87,y:311.37},Point {x:854.54,y:335.40},
-            Point {x:860.92,y:343.00},Point {x:849.84,y:359.59},Point {x:854.56,y:365.53},Point {x:844.09,y:371.89},
-            Point {x:839.57,y:390.40},Point {x:848.40,y:407.55},Point {x:839.51,y:432.76},Point {x:853.97,y:471.15},
-            Point {x:866.36,y:480.77}
+            Point2 {x:224.55,y:250.15},Point2 {x:267.76,y:213.81},Point2 {x:296.91,y:155.64},Point2 {x:330.33,y:137.57},
+            Point2 {x:414.82,y:139.75},Point2 {x:439.60,y:119.74},Point2 {x:474.93,y:107.87},Point2 {x:518.63,y:120.89},
+            Point2 {x:539.27,y:147.24},Point2 {x:617.74,y:159.86},Point2 {x:629.55,y:194.60},Point2 {x:671.55,y:222.55},
+            Point2 {x:727.81,y:213.36},Point2 {x:739.94,y:204.77},Point2 {x:769.98,y:208.42},Point2 {x:784.20,y:218.16},
+            Point2 {x:800.24,y:214.62},Point2 {x:820.77,y:236.17},Point2 {x:859.88,y:255.49},Point2 {x:865.21,y:268.53},
+            Point2 {x:857.95,y:280.30},Point2 {x:867.79,y:306.17},Point2 {x:858.29,y:314.94},Point2 {x:854.54,y:335.40},
+            Point2 {x:860.92,y:343.00},Point2 {x:839.57,y:390.40},Point2 {x:848.40,y:407.55},Point2 {x:839.51,y:432.76},
+            Point2 {x:853.97,y:471.15}
         ]);
 
         let actual = original.simplify(5.0, false);